@@ -10,6 +10,21 @@ mod types {
     use quote::{quote, ToTokens, TokenStreamExt};
     use serde::{Deserialize, Deserializer};
 
+    // Span and token/grouping fields are dropped from the debug dump.
+    fn is_token_noise(ty: &Type) -> bool {
+        match ty {
+            Type::Token(_) | Type::Group(_) => true,
+            Type::Syn(ident) if ident.0 == "MacroDelimiter" => true,
+            // Bare `proc_macro2::Span` fields (e.g. `Lifetime::apostrophe`) are
+            // span noise too.
+            Type::Ext(ident) if ident.0 == "Span" => true,
+            // Recurse through wrappers so e.g. `Option<Token![;]>` is dropped.
+            Type::Option(inner) | Type::Box(inner) | Type::Vec(inner) => is_token_noise(inner),
+            Type::Tuple(types) => !types.is_empty() && types.iter().all(is_token_noise),
+            _ => false,
+        }
+    }
+
     // Manual blacklist for now. See https://github.com/dtolnay/syn/issues/607#issuecomment-475905135.
     fn has_spanned(ty: &str) -> bool {
         match ty {
@@ -31,6 +46,10 @@ mod types {
 
     #[derive(Debug, PartialEq, Deserialize)]
     pub struct Definitions {
+        /// The `syn` release these definitions were generated from; surfaced as
+        /// the `_syn_version` key on every node (the definitions' crate version,
+        /// not a per-construct "since").
+        pub version: String,
         pub types: Vec<Node>,
         pub tokens: IndexMap<Ident, String>,
     }
@@ -47,14 +66,43 @@ mod types {
                             })
                         }
                     }
+
+                    impl CollectSpans for syn::token::#key {
+                        fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+                            record_span(out, stringify!(#key), self.span(), path);
+                        }
+                    }
+
+                    impl Normalize for syn::token::#key {
+                        fn normalized(&self) -> Self {
+                            syn::token::#key::default()
+                        }
+                    }
+
+                    impl ToDebug for syn::token::#key {
+                        fn to_debug(&self, out: &mut String, _indent: usize) {
+                            out.push_str(stringify!(#key));
+                        }
+                    }
                 });
             }
         }
     }
 
+    #[derive(Debug, PartialEq, Deserialize, Default)]
+    pub struct Features {
+        #[serde(default)]
+        pub any: Vec<String>,
+    }
+
     #[derive(Debug, PartialEq, Deserialize)]
     pub struct Node {
         pub ident: Ident,
+        #[serde(default)]
+        pub features: Features,
+        /// Filled in from the top-level `Definitions::version` before codegen.
+        #[serde(skip)]
+        pub since: String,
         #[serde(flatten, deserialize_with = "private_if_absent")]
         pub data: Data,
     }
@@ -63,14 +111,43 @@ mod types {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             let ident = &self.ident;
 
-            let data = match &self.data {
+            // Version/feature provenance, surfaced as extra keys on every node.
+            let since = &self.since;
+            let feature_list = &self.features.any;
+            let features_kv = if feature_list.is_empty() {
+                quote! {}
+            } else {
+                quote! { _features: vec![#(#feature_list.to_string()),*], }
+            };
+
+            let (data, collect, normalize, debug) = match &self.data {
                 Data::Private => {
-                    quote! {
+                    let data = quote! {
                         js!(#ident {
                             value: self.value(),
-                            span: self.span()
+                            span: self.span(),
+                            _syn_version: #since,
+                            #features_kv
                         })
-                    }
+                    };
+                    let collect = quote! {
+                        record_span(out, stringify!(#ident), self.span(), path);
+                    };
+                    // Opaque leaf: clone and reset its span to `call_site` so
+                    // literals compare equal regardless of source position.
+                    let normalize = quote! {
+                        {
+                            let mut node = self.clone();
+                            node.set_span(proc_macro2::Span::call_site());
+                            node
+                        }
+                    };
+                    let debug = quote! {
+                        out.push_str(concat!(stringify!(#ident), "("));
+                        self.value().to_debug(out, indent);
+                        out.push(')');
+                    };
+                    (data, collect, normalize, debug)
                 }
                 Data::Struct(fields) => {
                     let mut fields = fields.iter().collect::<Vec<_>>();
@@ -82,8 +159,8 @@ mod types {
                         _ => 0,
                     });
 
-                    let fields = fields
-                        .into_iter()
+                    let js_fields = fields
+                        .iter()
                         .map(|(field, _ty)| {
                             quote! {
                                 #field: self.#field
@@ -97,11 +174,79 @@ mod types {
                             None
                         });
 
-                    quote! {
+                    let data = quote! {
                         js!(#ident {
-                            #(#fields,)*
+                            #(#js_fields,)*
+                            _syn_version: #since,
+                            #features_kv
                         })
-                    }
+                    };
+
+                    // Recurse through the same fields the `js!` expansion walks,
+                    // pushing a field-name segment on entry and popping on exit.
+                    let recurse = fields.iter().map(|(field, _ty)| {
+                        quote! {
+                            path.push(PathSeg::Field(stringify!(#field)));
+                            self.#field.collect_spans(path, out);
+                            path.pop();
+                        }
+                    });
+
+                    let record = if has_spanned(&ident.0) {
+                        quote! {
+                            record_span(out, stringify!(#ident), self.span(), path);
+                        }
+                    } else {
+                        quote! {}
+                    };
+
+                    let collect = quote! {
+                        #(#recurse)*
+                        #record
+                    };
+
+                    // Rebuild the struct from every field, collapsing spans in
+                    // the token/span fields via their own `Normalize` impls.
+                    let norm_fields = fields.iter().map(|(field, _ty)| {
+                        quote! {
+                            #field: self.#field.normalized()
+                        }
+                    });
+                    let normalize = quote! {
+                        syn::#ident {
+                            #(#norm_fields,)*
+                        }
+                    };
+
+                    // Dump only the meaningful fields, skipping span/token noise.
+                    let kept = fields
+                        .iter()
+                        .filter(|(_field, ty)| !is_token_noise(ty))
+                        .collect::<Vec<_>>();
+                    let debug = if kept.is_empty() {
+                        quote! {
+                            out.push_str(stringify!(#ident));
+                        }
+                    } else {
+                        let debug_fields = kept.iter().map(|(field, _ty)| {
+                            quote! {
+                                out.push('\n');
+                                push_indent(out, indent + 1);
+                                out.push_str(concat!(stringify!(#field), ": "));
+                                self.#field.to_debug(out, indent + 1);
+                                out.push(',');
+                            }
+                        });
+                        quote! {
+                            out.push_str(concat!(stringify!(#ident), " {"));
+                            #(#debug_fields)*
+                            out.push('\n');
+                            push_indent(out, indent);
+                            out.push('}');
+                        }
+                    };
+
+                    (data, collect, normalize, debug)
                 }
                 Data::Enum(variants) => {
                     let matches = variants.iter().map(|(variant, types)| {
@@ -115,7 +260,7 @@ mod types {
 
                         match types.len() {
                             0 => quote! {
-                               #variant_path => js!(#variant {})
+                               #variant_path => js!(#variant { _syn_version: #since, #features_kv })
                             },
                             1 => quote! {
                                #variant_path(x) => x.to_js()
@@ -125,16 +270,151 @@ mod types {
                                 let payload = quote! { #(#payload),* };
 
                                 quote! {
-                                    #variant_path(#payload) => js!(#variant { span: self.span() } [#payload])
+                                    #variant_path(#payload) => js!(#variant { span: self.span(), _syn_version: #since, #features_kv } [#payload])
                                 }
                             }
                         }
                     });
-                    quote! {
+                    let data = quote! {
                         match self {
                             #(#matches,)*
                         }
-                    }
+                    };
+
+                    let collect_matches = variants.iter().map(|(variant, types)| {
+                        let variant_path = quote! {
+                            syn::#ident::#variant
+                        };
+
+                        match types.len() {
+                            0 => quote! {
+                                #variant_path => {}
+                            },
+                            1 => quote! {
+                                #variant_path(x) => x.collect_spans(path, out)
+                            },
+                            _ => {
+                                let payload = (0..types.len())
+                                    .map(|i| Ident(format!("x{}", i)))
+                                    .collect::<Vec<_>>();
+                                let recurse = payload.iter().enumerate().map(|(i, x)| {
+                                    let i = i as u32;
+                                    quote! {
+                                        path.push(PathSeg::Index(#i));
+                                        #x.collect_spans(path, out);
+                                        path.pop();
+                                    }
+                                });
+                                let payload = quote! { #(#payload),* };
+
+                                quote! {
+                                    #variant_path(#payload) => {
+                                        record_span(
+                                            out,
+                                            concat!(stringify!(#ident), "::", stringify!(#variant)),
+                                            self.span(),
+                                            path,
+                                        );
+                                        #(#recurse)*
+                                    }
+                                }
+                            }
+                        }
+                    });
+                    let collect = quote! {
+                        match self {
+                            #(#collect_matches,)*
+                        }
+                    };
+
+                    let norm_matches = variants.iter().map(|(variant, types)| {
+                        let variant_path = quote! {
+                            syn::#ident::#variant
+                        };
+
+                        match types.len() {
+                            0 => quote! {
+                                #variant_path => #variant_path
+                            },
+                            1 => quote! {
+                                #variant_path(x) => #variant_path(x.normalized())
+                            },
+                            _ => {
+                                let payload = (0..types.len())
+                                    .map(|i| Ident(format!("x{}", i)))
+                                    .collect::<Vec<_>>();
+                                let rebuilt = payload.iter().map(|x| {
+                                    quote! { #x.normalized() }
+                                });
+                                let payload = quote! { #(#payload),* };
+
+                                quote! {
+                                    #variant_path(#payload) => #variant_path(#(#rebuilt),*)
+                                }
+                            }
+                        }
+                    });
+                    let normalize = quote! {
+                        match self {
+                            #(#norm_matches,)*
+                        }
+                    };
+
+                    let debug_matches = variants.iter().map(|(variant, types)| {
+                        let variant_path = quote! {
+                            syn::#ident::#variant
+                        };
+                        let name = quote! {
+                            concat!(stringify!(#ident), "::", stringify!(#variant))
+                        };
+
+                        match types.len() {
+                            0 => quote! {
+                                #variant_path => out.push_str(#name)
+                            },
+                            1 => quote! {
+                                #variant_path(x) => {
+                                    out.push_str(#name);
+                                    out.push('(');
+                                    x.to_debug(out, indent);
+                                    out.push(')');
+                                }
+                            },
+                            _ => {
+                                let payload = (0..types.len())
+                                    .map(|i| Ident(format!("x{}", i)))
+                                    .collect::<Vec<_>>();
+                                let dumps = payload.iter().enumerate().map(|(i, x)| {
+                                    let sep = if i == 0 {
+                                        quote! {}
+                                    } else {
+                                        quote! { out.push_str(", "); }
+                                    };
+                                    quote! {
+                                        #sep
+                                        #x.to_debug(out, indent);
+                                    }
+                                });
+                                let payload = quote! { #(#payload),* };
+
+                                quote! {
+                                    #variant_path(#payload) => {
+                                        out.push_str(#name);
+                                        out.push('(');
+                                        #(#dumps)*
+                                        out.push(')');
+                                    }
+                                }
+                            }
+                        }
+                    });
+                    let debug = quote! {
+                        match self {
+                            #(#debug_matches,)*
+                        }
+                    };
+
+                    (data, collect, normalize, debug)
                 }
             };
 
@@ -144,6 +424,24 @@ mod types {
                         #data
                     }
                 }
+
+                impl CollectSpans for syn::#ident {
+                    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+                        #collect
+                    }
+                }
+
+                impl Normalize for syn::#ident {
+                    fn normalized(&self) -> Self {
+                        #normalize
+                    }
+                }
+
+                impl ToDebug for syn::#ident {
+                    fn to_debug(&self, out: &mut String, indent: usize) {
+                        #debug
+                    }
+                }
             });
         }
     }
@@ -204,7 +502,13 @@ mod types {
 }
 
 fn main() {
-    let body: types::Definitions = serde_json::from_str(include_str!("syn/syn.json")).unwrap();
+    let mut body: types::Definitions = serde_json::from_str(include_str!("syn/syn.json")).unwrap();
+
+    // Thread the top-level version onto each node so `to_tokens` can emit it.
+    let version = body.version.clone();
+    for node in &mut body.types {
+        node.since = version.clone();
+    }
 
     let generated = body.into_token_stream();
 
@@ -323,6 +323,598 @@ impl ToJS for syn::token::Bracket {
     }
 }
 
+/// A single step in the path from the root `ToJS` object down to a node: either
+/// an object field name or an array index. Indexing a `ToJS` tree by a slice of
+/// these reaches exactly the object the entry describes.
+enum PathSeg {
+    Field(&'static str),
+    Index(u32),
+}
+
+impl ToJS for PathSeg {
+    fn to_js(&self) -> JsValue {
+        match self {
+            PathSeg::Field(name) => name.to_js(),
+            PathSeg::Index(index) => index.to_js(),
+        }
+    }
+}
+
+/// One spanned node discovered while walking the AST, recorded with its source
+/// range and the path needed to index back into the `ToJS` tree.
+struct SpanEntry {
+    type_name: &'static str,
+    start: (u32, u32),
+    end: (u32, u32),
+    path: Vec<PathSeg>,
+}
+
+impl ToJS for SpanEntry {
+    fn to_js(&self) -> JsValue {
+        js!(SpanEntry {
+            type_name: self.type_name,
+            start: self.start,
+            end: self.end,
+            path: self.path,
+        })
+    }
+}
+
+fn record_span(
+    out: &mut Vec<SpanEntry>,
+    type_name: &'static str,
+    span: proc_macro2::Span,
+    path: &[PathSeg],
+) {
+    let start = span.start();
+    let end = span.end();
+    out.push(SpanEntry {
+        type_name,
+        start: (start.line as u32, start.column as u32),
+        end: (end.line as u32, end.column as u32),
+        path: path
+            .iter()
+            .map(|seg| match seg {
+                PathSeg::Field(name) => PathSeg::Field(name),
+                PathSeg::Index(index) => PathSeg::Index(*index),
+            })
+            .collect(),
+    });
+}
+
+/// Walks a node with exactly the same shape as the `ToJS` expansion, pushing a
+/// `PathSeg` on entry and popping it on exit, and recording a `SpanEntry` for
+/// every spanned node. The generated impls live alongside the `ToJS` impls.
+trait CollectSpans {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>);
+}
+
+macro_rules! collect_spans_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl CollectSpans for $ty {
+                fn collect_spans(&self, _path: &mut Vec<PathSeg>, _out: &mut Vec<SpanEntry>) {}
+            }
+        )*
+    };
+}
+
+collect_spans_leaf!(
+    bool,
+    u32,
+    f64,
+    u64,
+    usize,
+    u8,
+    char,
+    str,
+    String,
+    (),
+    proc_macro2::LineColumn,
+    proc_macro2::Span,
+    proc_macro2::Delimiter,
+    proc_macro2::Spacing,
+);
+
+impl<T: CollectSpans + ?Sized> CollectSpans for &'_ T {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        (**self).collect_spans(path, out);
+    }
+}
+
+impl<T: CollectSpans> CollectSpans for Option<T> {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        if let Some(value) = self {
+            value.collect_spans(path, out);
+        }
+    }
+}
+
+impl<T: CollectSpans + ?Sized> CollectSpans for Box<T> {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        (**self).collect_spans(path, out);
+    }
+}
+
+impl<T: CollectSpans> CollectSpans for [T] {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        for (i, item) in self.iter().enumerate() {
+            path.push(PathSeg::Index(i as u32));
+            item.collect_spans(path, out);
+            path.pop();
+        }
+    }
+}
+
+impl<T: CollectSpans> CollectSpans for Vec<T> {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        self.as_slice().collect_spans(path, out);
+    }
+}
+
+impl<A: CollectSpans, B: CollectSpans> CollectSpans for (A, B) {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        path.push(PathSeg::Index(0));
+        self.0.collect_spans(path, out);
+        path.pop();
+        path.push(PathSeg::Index(1));
+        self.1.collect_spans(path, out);
+        path.pop();
+    }
+}
+
+impl<A: CollectSpans, B: CollectSpans, C: CollectSpans> CollectSpans for (A, B, C) {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        path.push(PathSeg::Index(0));
+        self.0.collect_spans(path, out);
+        path.pop();
+        path.push(PathSeg::Index(1));
+        self.1.collect_spans(path, out);
+        path.pop();
+        path.push(PathSeg::Index(2));
+        self.2.collect_spans(path, out);
+        path.pop();
+    }
+}
+
+impl<T: CollectSpans, P: CollectSpans> CollectSpans for syn::punctuated::Punctuated<T, P> {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        let mut i = 0;
+        for pair in self.pairs() {
+            path.push(PathSeg::Index(i));
+            pair.value().collect_spans(path, out);
+            path.pop();
+            i += 1;
+            if let Some(punct) = pair.punct() {
+                path.push(PathSeg::Index(i));
+                punct.collect_spans(path, out);
+                path.pop();
+                i += 1;
+            }
+        }
+    }
+}
+
+impl CollectSpans for proc_macro2::Ident {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        record_span(out, "Ident", self.span(), path);
+    }
+}
+
+impl CollectSpans for proc_macro2::Punct {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        record_span(out, "Punct", self.span(), path);
+    }
+}
+
+impl CollectSpans for proc_macro2::Literal {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        record_span(out, "Literal", self.span(), path);
+    }
+}
+
+impl CollectSpans for proc_macro2::Group {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        record_span(out, "Group", self.span(), path);
+        path.push(PathSeg::Field("stream"));
+        self.stream().collect_spans(path, out);
+        path.pop();
+    }
+}
+
+impl CollectSpans for proc_macro2::TokenTree {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        match self {
+            proc_macro2::TokenTree::Group(group) => group.collect_spans(path, out),
+            proc_macro2::TokenTree::Ident(ident) => ident.collect_spans(path, out),
+            proc_macro2::TokenTree::Punct(punct) => punct.collect_spans(path, out),
+            proc_macro2::TokenTree::Literal(lit) => lit.collect_spans(path, out),
+        }
+    }
+}
+
+impl CollectSpans for proc_macro2::TokenStream {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        for (i, item) in self.clone().into_iter().enumerate() {
+            path.push(PathSeg::Index(i as u32));
+            item.collect_spans(path, out);
+            path.pop();
+        }
+    }
+}
+
+// The delimiter group tokens are not in `Definitions::tokens`, so (like their
+// `ToJS` impls) they are hand-written rather than generated.
+impl CollectSpans for syn::token::Paren {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        record_span(out, "Paren", self.span, path);
+    }
+}
+
+impl CollectSpans for syn::token::Brace {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        record_span(out, "Brace", self.span, path);
+    }
+}
+
+impl CollectSpans for syn::token::Bracket {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        record_span(out, "Bracket", self.span, path);
+    }
+}
+
+impl CollectSpans for syn::token::Group {
+    fn collect_spans(&self, path: &mut Vec<PathSeg>, out: &mut Vec<SpanEntry>) {
+        record_span(out, "Group", self.span, path);
+    }
+}
+
+/// Rebuilds a node into an equivalent one whose spans are all collapsed to
+/// `call_site` and whose token spacing/grouping is canonicalized, so that two
+/// structurally identical snippets normalize to the same value. The generated
+/// impls mirror the `ToJS` codegen shape, reconstructing every node field.
+trait Normalize {
+    fn normalized(&self) -> Self
+    where
+        Self: Sized;
+}
+
+macro_rules! normalize_clone {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Normalize for $ty {
+                fn normalized(&self) -> Self {
+                    self.clone()
+                }
+            }
+        )*
+    };
+}
+
+normalize_clone!(
+    bool,
+    u32,
+    f64,
+    u64,
+    usize,
+    u8,
+    char,
+    String,
+    (),
+    proc_macro2::Delimiter,
+    proc_macro2::Spacing,
+);
+
+impl<T: Normalize> Normalize for Option<T> {
+    fn normalized(&self) -> Self {
+        self.as_ref().map(Normalize::normalized)
+    }
+}
+
+impl<T: Normalize> Normalize for Box<T> {
+    fn normalized(&self) -> Self {
+        Box::new((**self).normalized())
+    }
+}
+
+impl<T: Normalize> Normalize for Vec<T> {
+    fn normalized(&self) -> Self {
+        self.iter().map(Normalize::normalized).collect()
+    }
+}
+
+impl<A: Normalize, B: Normalize> Normalize for (A, B) {
+    fn normalized(&self) -> Self {
+        (self.0.normalized(), self.1.normalized())
+    }
+}
+
+impl<A: Normalize, B: Normalize, C: Normalize> Normalize for (A, B, C) {
+    fn normalized(&self) -> Self {
+        (self.0.normalized(), self.1.normalized(), self.2.normalized())
+    }
+}
+
+impl<T: Normalize, P: Normalize> Normalize for syn::punctuated::Punctuated<T, P> {
+    fn normalized(&self) -> Self {
+        let mut out = syn::punctuated::Punctuated::new();
+        for pair in self.pairs() {
+            match pair {
+                syn::punctuated::Pair::Punctuated(value, punct) => {
+                    out.push_value(value.normalized());
+                    out.push_punct(punct.normalized());
+                }
+                syn::punctuated::Pair::End(value) => {
+                    out.push_value(value.normalized());
+                }
+            }
+        }
+        out
+    }
+}
+
+impl Normalize for proc_macro2::Span {
+    fn normalized(&self) -> Self {
+        proc_macro2::Span::call_site()
+    }
+}
+
+impl Normalize for proc_macro2::Ident {
+    fn normalized(&self) -> Self {
+        proc_macro2::Ident::new(&self.to_string(), proc_macro2::Span::call_site())
+    }
+}
+
+impl Normalize for proc_macro2::Literal {
+    fn normalized(&self) -> Self {
+        let mut lit = self.clone();
+        lit.set_span(proc_macro2::Span::call_site());
+        lit
+    }
+}
+
+impl Normalize for proc_macro2::Punct {
+    fn normalized(&self) -> Self {
+        // Drop the recorded spacing; canonicalize to `Alone`.
+        proc_macro2::Punct::new(self.as_char(), proc_macro2::Spacing::Alone)
+    }
+}
+
+impl Normalize for proc_macro2::Group {
+    fn normalized(&self) -> Self {
+        let mut group = proc_macro2::Group::new(self.delimiter(), self.stream().normalized());
+        group.set_span(proc_macro2::Span::call_site());
+        group
+    }
+}
+
+impl Normalize for proc_macro2::TokenTree {
+    fn normalized(&self) -> Self {
+        match self {
+            proc_macro2::TokenTree::Group(group) => group.normalized().into(),
+            proc_macro2::TokenTree::Ident(ident) => ident.normalized().into(),
+            proc_macro2::TokenTree::Punct(punct) => punct.normalized().into(),
+            proc_macro2::TokenTree::Literal(lit) => lit.normalized().into(),
+        }
+    }
+}
+
+impl Normalize for proc_macro2::TokenStream {
+    fn normalized(&self) -> Self {
+        self.clone().into_iter().map(|tt| tt.normalized()).collect()
+    }
+}
+
+// The delimiter group tokens are not in `Definitions::tokens`; like the
+// generated token loop, normalization resets them to a `call_site` default.
+impl Normalize for syn::token::Paren {
+    fn normalized(&self) -> Self {
+        syn::token::Paren::default()
+    }
+}
+
+impl Normalize for syn::token::Brace {
+    fn normalized(&self) -> Self {
+        syn::token::Brace::default()
+    }
+}
+
+impl Normalize for syn::token::Bracket {
+    fn normalized(&self) -> Self {
+        syn::token::Bracket::default()
+    }
+}
+
+impl Normalize for syn::token::Group {
+    fn normalized(&self) -> Self {
+        syn::token::Group::default()
+    }
+}
+
+/// Builds a compact, span-free, indented textual dump of a node — the kind of
+/// output syn's `debug.rs` codegen produces. The generated impls mirror the
+/// `ToJS` shape but omit every span and token/grouping field.
+trait ToDebug {
+    fn to_debug(&self, out: &mut String, indent: usize);
+}
+
+fn push_indent(out: &mut String, indent: usize) {
+    for _ in 0..indent {
+        out.push_str("    ");
+    }
+}
+
+impl<T: ToDebug + ?Sized> ToDebug for &'_ T {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        (**self).to_debug(out, indent);
+    }
+}
+
+impl ToDebug for bool {
+    fn to_debug(&self, out: &mut String, _indent: usize) {
+        out.push_str(if *self { "true" } else { "false" });
+    }
+}
+
+macro_rules! to_debug_display {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ToDebug for $ty {
+                fn to_debug(&self, out: &mut String, _indent: usize) {
+                    out.push_str(&self.to_string());
+                }
+            }
+        )*
+    };
+}
+
+to_debug_display!(u32, f64, u64, usize, u8, proc_macro2::Ident, proc_macro2::Literal);
+
+impl ToDebug for char {
+    fn to_debug(&self, out: &mut String, _indent: usize) {
+        out.push_str(&format!("{:?}", self));
+    }
+}
+
+impl ToDebug for str {
+    fn to_debug(&self, out: &mut String, _indent: usize) {
+        out.push_str(&format!("{:?}", self));
+    }
+}
+
+impl ToDebug for String {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        self.as_str().to_debug(out, indent);
+    }
+}
+
+impl ToDebug for () {
+    fn to_debug(&self, out: &mut String, _indent: usize) {
+        out.push_str("()");
+    }
+}
+
+impl<T: ToDebug> ToDebug for Option<T> {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        match self {
+            Some(value) => {
+                out.push_str("Some(");
+                value.to_debug(out, indent);
+                out.push(')');
+            }
+            None => out.push_str("None"),
+        }
+    }
+}
+
+impl<T: ToDebug + ?Sized> ToDebug for Box<T> {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        (**self).to_debug(out, indent);
+    }
+}
+
+impl<T: ToDebug> ToDebug for [T] {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        if self.is_empty() {
+            out.push_str("[]");
+            return;
+        }
+        out.push('[');
+        for item in self {
+            out.push('\n');
+            push_indent(out, indent + 1);
+            item.to_debug(out, indent + 1);
+            out.push(',');
+        }
+        out.push('\n');
+        push_indent(out, indent);
+        out.push(']');
+    }
+}
+
+impl<T: ToDebug> ToDebug for Vec<T> {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        self.as_slice().to_debug(out, indent);
+    }
+}
+
+impl<A: ToDebug, B: ToDebug> ToDebug for (A, B) {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        out.push('(');
+        self.0.to_debug(out, indent);
+        out.push_str(", ");
+        self.1.to_debug(out, indent);
+        out.push(')');
+    }
+}
+
+impl<A: ToDebug, B: ToDebug, C: ToDebug> ToDebug for (A, B, C) {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        out.push('(');
+        self.0.to_debug(out, indent);
+        out.push_str(", ");
+        self.1.to_debug(out, indent);
+        out.push_str(", ");
+        self.2.to_debug(out, indent);
+        out.push(')');
+    }
+}
+
+impl<T: ToDebug, P> ToDebug for syn::punctuated::Punctuated<T, P> {
+    fn to_debug(&self, out: &mut String, indent: usize) {
+        // Drop the punctuation (token noise); dump the values as a list.
+        if self.is_empty() {
+            out.push_str("[]");
+            return;
+        }
+        out.push('[');
+        for value in self.iter() {
+            out.push('\n');
+            push_indent(out, indent + 1);
+            value.to_debug(out, indent + 1);
+            out.push(',');
+        }
+        out.push('\n');
+        push_indent(out, indent);
+        out.push(']');
+    }
+}
+
+impl ToDebug for proc_macro2::Span {
+    fn to_debug(&self, out: &mut String, _indent: usize) {
+        // The dump is span-free; bare spans collapse to a placeholder.
+        out.push('_');
+    }
+}
+
+impl ToDebug for proc_macro2::TokenTree {
+    fn to_debug(&self, out: &mut String, _indent: usize) {
+        out.push_str(&self.to_string());
+    }
+}
+
+impl ToDebug for proc_macro2::TokenStream {
+    fn to_debug(&self, out: &mut String, _indent: usize) {
+        out.push_str(&format!("{:?}", self.to_string()));
+    }
+}
+
+// The delimiter group tokens are not in `Definitions::tokens`; like the
+// generated token impls, they dump as their bare name.
+macro_rules! to_debug_group {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl ToDebug for syn::token::$ty {
+                fn to_debug(&self, out: &mut String, _indent: usize) {
+                    out.push_str(stringify!($ty));
+                }
+            }
+        )*
+    };
+}
+
+to_debug_group!(Paren, Brace, Bracket, Group);
+
 include!(concat!(env!("OUT_DIR"), "/to_js.rs"));
 
 impl ToJS for syn::Error {
@@ -349,12 +941,197 @@ pub fn parse_derive_input(rust: &str) -> Result<JsValue, JsValue> {
     }
 }
 
+fn span_contains(entry: &SpanEntry, pos: (u32, u32)) -> bool {
+    entry.start <= pos && pos <= entry.end
+}
+
+fn span_area(entry: &SpanEntry) -> (u32, i64) {
+    (
+        entry.end.0 - entry.start.0,
+        i64::from(entry.end.1) - i64::from(entry.start.1),
+    )
+}
+
+#[wasm_bindgen(js_name = "spanIndex")]
+pub fn span_index(rust: &str) -> Result<JsValue, JsValue> {
+    match syn::parse_file(rust) {
+        Ok(ast) => {
+            let mut out = Vec::new();
+            ast.collect_spans(&mut Vec::new(), &mut out);
+            Ok(out.to_js())
+        }
+        Err(err) => Err(err.to_js()),
+    }
+}
+
+#[wasm_bindgen(js_name = "nodeAtPosition")]
+pub fn node_at_position(rust: &str, line: u32, column: u32) -> Result<JsValue, JsValue> {
+    let ast = match syn::parse_file(rust) {
+        Ok(ast) => ast,
+        Err(err) => return Err(err.to_js()),
+    };
+
+    let mut entries = Vec::new();
+    ast.collect_spans(&mut Vec::new(), &mut entries);
+
+    let pos = (line, column);
+    let best = entries
+        .into_iter()
+        .filter(|entry| span_contains(entry, pos))
+        // Smallest spanned area is the deepest/innermost node; break ties by
+        // the longest path so the most specific node wins.
+        .min_by(|a, b| {
+            span_area(a)
+                .cmp(&span_area(b))
+                .then(b.path.len().cmp(&a.path.len()))
+        });
+
+    Ok(match best {
+        Some(entry) => entry.path.to_js(),
+        None => JsValue::UNDEFINED,
+    })
+}
+
+#[wasm_bindgen(js_name = "normalize")]
+pub fn normalize(rust: &str) -> Result<JsValue, JsValue> {
+    match syn::parse_file(rust) {
+        Ok(ast) => {
+            let normalized = ast.normalized();
+            let obj = new_object_with_type("Normalized");
+            obj.set("ast", normalized.to_js());
+            obj.set("source", normalized.into_token_stream().to_string().to_js());
+            Ok(obj.into())
+        }
+        Err(err) => Err(err.to_js()),
+    }
+}
+
+fn parse_as<T: syn::parse::Parse + ToJS>(rust: &str) -> Result<JsValue, JsValue> {
+    match syn::parse_str::<T>(rust) {
+        Ok(ast) => Ok(ast.to_js()),
+        Err(err) => Err(err.to_js()),
+    }
+}
+
+// `Pat` does not implement `Parse` either; parse it inside a `let` binding (a
+// context that yields a `Pat`) and pull the pattern back out of the `Local`.
+fn parse_pat_str(rust: &str) -> Result<JsValue, JsValue> {
+    let wrapped = format!("let {} = ();", rust);
+    match syn::parse::Parser::parse_str(syn::Block::parse_within, &wrapped) {
+        Ok(stmts) => match stmts.into_iter().next() {
+            Some(syn::Stmt::Local(local)) => Ok(local.pat.to_js()),
+            _ => Err(SyntaxError::new("expected a pattern").into()),
+        },
+        Err(err) => Err(err.to_js()),
+    }
+}
+
+#[wasm_bindgen(js_name = "parse")]
+pub fn parse(kind: &str, rust: &str) -> Result<JsValue, JsValue> {
+    match kind {
+        "Expr" => parse_as::<syn::Expr>(rust),
+        "Item" => parse_as::<syn::Item>(rust),
+        // `Stmt` does not implement `Parse`; parse the statements within a block.
+        "Stmt" => match syn::parse::Parser::parse_str(syn::Block::parse_within, rust) {
+            Ok(stmts) => Ok(stmts.to_js()),
+            Err(err) => Err(err.to_js()),
+        },
+        "Type" => parse_as::<syn::Type>(rust),
+        "Pat" => parse_pat_str(rust),
+        "Path" => parse_as::<syn::Path>(rust),
+        "TokenStream" => parse_as::<proc_macro2::TokenStream>(rust),
+        other => Err(SyntaxError::new(&format!("unknown parse kind: {}", other)).into()),
+    }
+}
+
+#[wasm_bindgen(js_name = "parseExpr")]
+pub fn parse_expr(rust: &str) -> Result<JsValue, JsValue> {
+    parse("Expr", rust)
+}
+
+#[wasm_bindgen(js_name = "parseItem")]
+pub fn parse_item(rust: &str) -> Result<JsValue, JsValue> {
+    parse("Item", rust)
+}
+
+#[wasm_bindgen(js_name = "parseStmt")]
+pub fn parse_stmt(rust: &str) -> Result<JsValue, JsValue> {
+    parse("Stmt", rust)
+}
+
+#[wasm_bindgen(js_name = "parseType")]
+pub fn parse_type(rust: &str) -> Result<JsValue, JsValue> {
+    parse("Type", rust)
+}
+
+#[wasm_bindgen(js_name = "parsePat")]
+pub fn parse_pat(rust: &str) -> Result<JsValue, JsValue> {
+    parse("Pat", rust)
+}
+
+#[wasm_bindgen(js_name = "parsePath")]
+pub fn parse_path(rust: &str) -> Result<JsValue, JsValue> {
+    parse("Path", rust)
+}
+
+#[wasm_bindgen(js_name = "parseTokenStream")]
+pub fn parse_token_stream(rust: &str) -> Result<JsValue, JsValue> {
+    parse("TokenStream", rust)
+}
+
+fn pretty_or_dense(tokens: proc_macro2::TokenStream, pretty: bool) -> String {
+    let dense = tokens.to_string();
+    if pretty {
+        // `prettyplease` only formats a whole `syn::File`, so re-parse the
+        // emitted tokens as one. This covers the file-level kinds (`File`,
+        // `Item`, `DeriveInput`); fragment kinds (`Expr`, `Stmt`, `Type`) can't
+        // stand alone as a file, so say so instead of silently falling back to
+        // the dense form.
+        match syn::parse2::<syn::File>(tokens) {
+            Ok(file) => return prettyplease::unparse(&file),
+            Err(_) => {
+                return format!(
+                    "// pretty-printing is only supported for file-level kinds; showing compact form\n{}",
+                    dense
+                )
+            }
+        }
+    }
+    dense
+}
+
+#[wasm_bindgen(js_name = "debugDump")]
+pub fn debug_dump(rust: &str) -> Result<JsValue, JsValue> {
+    match syn::parse_file(rust) {
+        Ok(ast) => {
+            let mut out = String::new();
+            ast.to_debug(&mut out, 0);
+            Ok(out.to_js())
+        }
+        Err(err) => Err(err.to_js()),
+    }
+}
+
 #[wasm_bindgen(js_name = "printAst")]
-pub fn print_ast(ast: &str) -> Result<JsValue, JsValue> {
-    let file_result: Result<syn::File, _> = syn_serde::json::from_str(ast);
-    
-    match file_result {
-        Ok(file) => Ok(file.into_token_stream().to_string().to_js()),
-        Err(err) => Err(err.to_string().to_js())
+pub fn print_ast(kind: &str, json: &str, pretty: bool) -> Result<JsValue, JsValue> {
+    // Inline the concrete type per kind so the `syn_serde` deserialize bound is
+    // discharged by the type itself rather than a named `serde` bound here.
+    macro_rules! print_kind {
+        ($ty:ty) => {
+            match syn_serde::json::from_str::<$ty>(json) {
+                Ok(value) => Ok(pretty_or_dense(value.into_token_stream(), pretty).to_js()),
+                Err(err) => Err(err.to_string().to_js()),
+            }
+        };
+    }
+
+    match kind {
+        "File" => print_kind!(syn::File),
+        "Item" => print_kind!(syn::Item),
+        "Expr" => print_kind!(syn::Expr),
+        "DeriveInput" => print_kind!(syn::DeriveInput),
+        "Stmt" => print_kind!(syn::Stmt),
+        "Type" => print_kind!(syn::Type),
+        other => Err(SyntaxError::new(&format!("unknown print kind: {}", other)).into()),
     }
 }
\ No newline at end of file